@@ -1,8 +1,50 @@
 use bit_vec::BitVec;
 use std::collections::hash_map::{DefaultHasher, RandomState};
-use std::hash::{BuildHasher, Hash, Hasher};
+use std::collections::BTreeSet;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
 use std::marker::PhantomData;
 
+/// Number of bits packed into each journalled word.
+const WORD_BITS: usize = 64;
+
+/// Marker for [`BuildHasher`]s whose output is fully determined by the seed,
+/// so a filter built with one round-trips through [`into_parts`] /
+/// [`from_parts`] bit-for-bit.
+///
+/// It is deliberately **not** implemented for [`RandomState`]: its per-instance
+/// keys are not captured by the serialized seeds, so serializing a filter that
+/// uses it and rebuilding it would silently produce false negatives. Gating the
+/// serialization API on this trait turns that into a compile error instead.
+///
+/// [`into_parts`]: StandardBloomFilter::into_parts
+/// [`from_parts`]: StandardBloomFilter::from_parts
+pub trait SeedDeterministic {}
+
+impl<H: Default + Hasher> SeedDeterministic for BuildHasherDefault<H> {}
+
+/// Draws a pseudo-random `u64` seed from a fresh [`RandomState`].
+fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Calculates the optimal bitmap size based on expected items and false positive rate.
+fn bitmap_size(items_count: usize, fp_rate: f64) -> usize {
+    let ln2_2 = core::f64::consts::LN_2.powi(2);
+    ((-1.0f64 * items_count as f64 * fp_rate.ln()) / ln2_2).ceil() as usize
+}
+
+/// Calculates the optimal number of hash functions based on false positive rate.
+fn optimal_k(fp_rate: f64) -> u32 {
+    (-(fp_rate.ln() / core::f64::consts::LN_2)).ceil() as u32
+}
+
+/// Computes the index using double hashing, `(h1 + k_i * h2) mod len`.
+#[inline]
+fn get_index(h1: u64, h2: u64, k_i: u64, len: usize) -> usize {
+    let combined_hash = h1.wrapping_add(k_i.wrapping_mul(h2));
+    (combined_hash % len as u64) as usize
+}
+
 /// A standard Bloom filter implementation.
 ///
 /// A Bloom filter is a space-efficient probabilistic data structure that is used
@@ -11,6 +53,11 @@ use std::marker::PhantomData;
 ///
 /// # Type Parameters
 /// - `T`: The type of items to store. Must implement `Hash`.
+/// - `S`: The [`BuildHasher`] used to seed the two hash functions. Defaults to
+///   [`RandomState`]. Plug in a faster non-cryptographic hasher (xxHash,
+///   fxhash, …) for throughput-sensitive workloads via [`with_hashers`].
+///
+/// [`with_hashers`]: StandardBloomFilter::with_hashers
 ///
 /// # Example
 /// ```
@@ -21,16 +68,18 @@ use std::marker::PhantomData;
 /// assert!(filter.contains("hello"));
 /// assert!(!filter.contains("world")); // Might be true due to false positive
 /// ```
-pub struct StandardBloomFilter<T: ?Sized + Hash> {
+pub struct StandardBloomFilter<T: ?Sized + Hash, S: BuildHasher = RandomState> {
     bitmap: BitVec,
     optimal_k: u32,
-    hashers: [DefaultHasher; 2],
+    build_hashers: [S; 2],
+    seeds: [u64; 2],
+    journal: Option<BTreeSet<usize>>,
     _marker: PhantomData<T>,
 }
 
-impl<T: ?Sized + Hash> StandardBloomFilter<T> {
+impl<T: ?Sized + Hash> StandardBloomFilter<T, RandomState> {
     /// Creates a new Bloom filter optimized for the expected number of items
-    /// and desired false positive rate.
+    /// and desired false positive rate, seeded with [`RandomState`].
     ///
     /// # Arguments
     /// * `items_count` - Expected number of items to be inserted
@@ -39,38 +88,81 @@ impl<T: ?Sized + Hash> StandardBloomFilter<T> {
     /// # Panics
     /// Panics if `fp_rate` is not in the range (0, 1) or if `items_count` is 0.
     pub fn new(items_count: usize, fp_rate: f64) -> Self {
+        Self::with_hashers(items_count, fp_rate, RandomState::new(), RandomState::new())
+    }
+}
+
+impl<T: ?Sized + Hash, S: BuildHasher + Default + SeedDeterministic> StandardBloomFilter<T, S> {
+    /// Reconstructs a filter from parts previously obtained via [`into_parts`].
+    ///
+    /// `parts` is the backing bitmap as packed 64-bit words, `optimal_k` the
+    /// number of hash functions, and `seeds` the two hasher seeds. The hasher
+    /// builders are rebuilt with `S::default()`; the [`SeedDeterministic`] bound
+    /// guarantees the rebuilt filter addresses bits identically to the original,
+    /// so membership round-trips without introducing false negatives.
+    ///
+    /// [`into_parts`]: StandardBloomFilter::into_parts
+    pub fn from_parts(parts: &[u64], optimal_k: u32, seeds: [u64; 2]) -> Self {
+        let mut bitmap = BitVec::from_elem(parts.len() * WORD_BITS, false);
+        for (word_index, &word) in parts.iter().enumerate() {
+            for bit in 0..WORD_BITS {
+                if word & (1u64 << bit) != 0 {
+                    bitmap.set(word_index * WORD_BITS + bit, true);
+                }
+            }
+        }
+
+        Self {
+            bitmap,
+            optimal_k,
+            build_hashers: [S::default(), S::default()],
+            seeds,
+            journal: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized + Hash, S: BuildHasher> StandardBloomFilter<T, S> {
+    /// Creates a new Bloom filter seeded with two caller-provided
+    /// [`BuildHasher`]s.
+    ///
+    /// The two `BuildHasher`s should be independently seeded so that the two
+    /// hash values are uncorrelated; `hash_kernel` builds a fresh hasher from
+    /// each for every lookup rather than reusing a partially-consumed state.
+    ///
+    /// # Arguments
+    /// * `items_count` - Expected number of items to be inserted
+    /// * `fp_rate` - Desired false positive rate (e.g., 0.01 for 1%)
+    /// * `s1`, `s2` - The two hasher builders
+    ///
+    /// # Panics
+    /// Panics if `fp_rate` is not in the range (0, 1) or if `items_count` is 0.
+    pub fn with_hashers(items_count: usize, fp_rate: f64, s1: S, s2: S) -> Self {
         assert!(items_count > 0, "items_count must be greater than 0");
         assert!(
             fp_rate > 0.0 && fp_rate < 1.0,
             "fp_rate must be between 0 and 1 (exclusive)"
         );
 
-        let optimal_m = Self::bitmap_size(items_count, fp_rate);
-        let optimal_k = Self::optimal_k(fp_rate);
-        let hashers = [
-            RandomState::new().build_hasher(),
-            RandomState::new().build_hasher(),
-        ];
+        // Round the bitmap up to a whole number of 64-bit words so the backing
+        // storage round-trips exactly through `into_parts`/`from_parts`.
+        let optimal_m = bitmap_size(items_count, fp_rate)
+            .div_ceil(WORD_BITS)
+            .max(1)
+            * WORD_BITS;
+        let optimal_k = optimal_k(fp_rate);
 
         Self {
             bitmap: BitVec::from_elem(optimal_m, false),
             optimal_k,
-            hashers,
+            build_hashers: [s1, s2],
+            seeds: [random_seed(), random_seed()],
+            journal: None,
             _marker: PhantomData,
         }
     }
 
-    /// Calculates the optimal bitmap size based on expected items and false positive rate.
-    fn bitmap_size(items_count: usize, fp_rate: f64) -> usize {
-        let ln2_2 = core::f64::consts::LN_2.powi(2);
-        ((-1.0f64 * items_count as f64 * fp_rate.ln()) / ln2_2).ceil() as usize
-    }
-
-    /// Calculates the optimal number of hash functions based on false positive rate.
-    fn optimal_k(fp_rate: f64) -> u32 {
-        (-(fp_rate.ln() / core::f64::consts::LN_2)).ceil() as u32
-    }
-
     /// Inserts an item into the Bloom filter.
     ///
     /// # Arguments
@@ -80,8 +172,13 @@ impl<T: ?Sized + Hash> StandardBloomFilter<T> {
         let len = self.bitmap.len();
 
         for k_i in 0..self.optimal_k {
-            let index = Self::get_index(h1, h2, k_i as u64, len);
-            self.bitmap.set(index, true);
+            let index = get_index(h1, h2, k_i as u64, len);
+            if !self.bitmap.get(index).unwrap_or(false) {
+                self.bitmap.set(index, true);
+                if let Some(journal) = &mut self.journal {
+                    journal.insert(index / WORD_BITS);
+                }
+            }
         }
     }
 
@@ -98,7 +195,7 @@ impl<T: ?Sized + Hash> StandardBloomFilter<T> {
         let len = self.bitmap.len();
 
         for k_i in 0..self.optimal_k {
-            let index = Self::get_index(h1, h2, k_i as u64, len);
+            let index = get_index(h1, h2, k_i as u64, len);
             if !self.bitmap.get(index).unwrap_or(false) {
                 return false;
             }
@@ -108,8 +205,15 @@ impl<T: ?Sized + Hash> StandardBloomFilter<T> {
     }
 
     /// Clears all items from the Bloom filter.
+    ///
+    /// When journalling is enabled every word is marked touched, so a
+    /// subsequent [`drain_journal`] reports the now-cleared words and an
+    /// incremental persister doesn't keep stale set-bits on disk.
+    ///
+    /// [`drain_journal`]: StandardBloomFilter::drain_journal
     pub fn clear(&mut self) {
         self.bitmap.clear();
+        self.mark_all_journalled();
     }
 
     /// Returns the size of the underlying bitmap in bits.
@@ -127,24 +231,388 @@ impl<T: ?Sized + Hash> StandardBloomFilter<T> {
         self.optimal_k
     }
 
+    /// Returns the number of bits currently set, via word-level popcount over
+    /// the backing storage.
+    pub fn count_ones(&self) -> usize {
+        self.bitmap
+            .blocks()
+            .map(|block| block.count_ones() as usize)
+            .sum()
+    }
+
+    /// Estimates the number of distinct elements inserted so far.
+    ///
+    /// Uses the standard Bloom identity `n ≈ -(m/k) · ln(1 - x/m)`, where `m`
+    /// is the bitmap size, `k` the number of hash functions and `x` the number
+    /// of set bits. Returns [`f64::INFINITY`] once the bitmap has saturated
+    /// (`x == m`), at which point the estimate is unbounded.
+    pub fn estimate_count(&self) -> f64 {
+        let m = self.bitmap.len() as f64;
+        let k = self.optimal_k as f64;
+        let x = self.count_ones() as f64;
+
+        if x >= m {
+            return f64::INFINITY;
+        }
+
+        -(m / k) * (1.0 - x / m).ln()
+    }
+
+    /// Estimates the current false-positive probability, `(x/m)^k`.
+    ///
+    /// As the filter fills this drifts above the `fp_rate` it was constructed
+    /// with; comparing the two reveals whether the filter has exceeded its
+    /// designed capacity. Returns `1.0` once the bitmap has saturated.
+    pub fn estimated_fp_rate(&self) -> f64 {
+        let m = self.bitmap.len() as f64;
+        let x = self.count_ones() as f64;
+
+        (x / m).powi(self.optimal_k as i32)
+    }
+
     /// Computes two independent hash values for the given item.
+    ///
+    /// Each hasher is seeded by writing the corresponding entry of `seeds`
+    /// before the item, so the two values are independently seeded and — given
+    /// a deterministic `S` — reproducible from the serialized seeds alone.
     fn hash_kernel(&self, item: &T) -> (u64, u64) {
-        let mut hasher1 = self.hashers[0].clone();
+        let mut hasher1 = self.build_hashers[0].build_hasher();
+        hasher1.write_u64(self.seeds[0]);
         item.hash(&mut hasher1);
         let h1 = hasher1.finish();
 
-        let mut hasher2 = self.hashers[1].clone();
+        let mut hasher2 = self.build_hashers[1].build_hasher();
+        hasher2.write_u64(self.seeds[1]);
         item.hash(&mut hasher2);
         let h2 = hasher2.finish();
 
         (h1, h2)
     }
 
-    /// Computes the bit index using enhanced double hashing.
-    #[inline]
-    fn get_index(h1: u64, h2: u64, k_i: u64, len: usize) -> usize {
-        let combined_hash = h1.wrapping_add(k_i.wrapping_mul(h2));
-        (combined_hash % len as u64) as usize
+    /// Returns the hasher seeds, needed to reconstruct or merge the filter.
+    pub fn seeds(&self) -> [u64; 2] {
+        self.seeds
+    }
+
+    /// Merges `other` into `self` by OR-ing their bitmaps.
+    ///
+    /// This lets partial filters built independently (per shard or per thread)
+    /// be combined into one. Union preserves the no-false-negatives guarantee:
+    /// every element present in either operand remains present in the result.
+    ///
+    /// # Panics
+    /// Panics unless the filters are structurally identical — same bitmap
+    /// length, same number of hash functions, and the same hasher seeds —
+    /// since only then do they address bits the same way.
+    pub fn union(&mut self, other: &Self) {
+        self.assert_compatible(other);
+        self.bitmap.or(&other.bitmap);
+        self.mark_all_journalled();
+    }
+
+    /// Combines `other` into `self` by AND-ing their bitmaps.
+    ///
+    /// An element present in both operands is retained, but unlike [`union`]
+    /// this can introduce false negatives: a bit that one filter set for some
+    /// element may be clear in the other, dropping an element that was in fact
+    /// inserted into one of them.
+    ///
+    /// [`union`]: StandardBloomFilter::union
+    ///
+    /// # Panics
+    /// Panics unless the filters are structurally identical (see [`union`]).
+    pub fn intersection(&mut self, other: &Self) {
+        self.assert_compatible(other);
+        self.bitmap.and(&other.bitmap);
+        self.mark_all_journalled();
+    }
+
+    /// Asserts that `other` can be combined with `self` bit-for-bit.
+    fn assert_compatible(&self, other: &Self) {
+        assert_eq!(
+            self.bitmap.len(),
+            other.bitmap.len(),
+            "filters must have the same bitmap length to be combined"
+        );
+        assert_eq!(
+            self.optimal_k, other.optimal_k,
+            "filters must use the same number of hash functions to be combined"
+        );
+        assert_eq!(
+            self.seeds, other.seeds,
+            "filters must share the same hash seeds to be combined"
+        );
+    }
+
+    /// Marks every word as touched when journalling is active, since a set
+    /// operation may have altered any of them.
+    fn mark_all_journalled(&mut self) {
+        let word_count = self.bitmap.len() / WORD_BITS;
+        if let Some(journal) = &mut self.journal {
+            journal.extend(0..word_count);
+        }
+    }
+
+    /// Reads the `word_index`-th 64-bit word of the backing bitmap.
+    fn word(&self, word_index: usize) -> u64 {
+        let base = word_index * WORD_BITS;
+        let mut word = 0u64;
+        for bit in 0..WORD_BITS {
+            if self.bitmap.get(base + bit).unwrap_or(false) {
+                word |= 1u64 << bit;
+            }
+        }
+        word
+    }
+
+    /// Enables journalling, recording which 64-bit words change from now on.
+    ///
+    /// Use together with [`drain_journal`] to persist large, continuously
+    /// updated filters incrementally instead of rewriting the whole bitmap.
+    ///
+    /// [`drain_journal`]: StandardBloomFilter::drain_journal
+    pub fn enable_journal(&mut self) {
+        self.journal.get_or_insert_with(BTreeSet::new);
+    }
+
+    /// Drains the journal, yielding the `(word_index, word_value)` pairs for
+    /// every 64-bit word touched since the last drain.
+    ///
+    /// Returns an empty vector if journalling was never enabled. Journalling
+    /// remains enabled afterwards so subsequent changes are tracked again.
+    pub fn drain_journal(&mut self) -> Vec<(usize, u64)> {
+        let Some(journal) = &mut self.journal else {
+            return Vec::new();
+        };
+        let touched: Vec<usize> = std::mem::take(journal).into_iter().collect();
+        touched
+            .into_iter()
+            .map(|word_index| (word_index, self.word(word_index)))
+            .collect()
+    }
+}
+
+impl<T: ?Sized + Hash, S: BuildHasher + SeedDeterministic> StandardBloomFilter<T, S> {
+    /// Decomposes the filter into its serializable parts.
+    ///
+    /// Returns the backing bitmap as packed 64-bit words together with
+    /// `optimal_k` and the hasher `seeds` — everything [`from_parts`] needs to
+    /// rebuild the filter bit-for-bit. Only available for [`SeedDeterministic`]
+    /// hashers, since the parts are only reconstructable when the seeds fully
+    /// determine addressing.
+    ///
+    /// [`from_parts`]: StandardBloomFilter::from_parts
+    pub fn into_parts(&self) -> (Vec<u64>, u32, [u64; 2]) {
+        let word_count = self.bitmap.len() / WORD_BITS;
+        let words = (0..word_count).map(|i| self.word(i)).collect();
+        (words, self.optimal_k, self.seeds)
+    }
+}
+
+/// A single counter cell in a [`CountingBloomFilter`].
+///
+/// Implementors provide a small unsigned integer that saturates on overflow
+/// rather than wrapping, so that a counter which reaches its ceiling can be
+/// treated as permanently "stuck" and never wrongly decremented back to zero.
+pub trait CounterStorage: Copy + Default {
+    /// The largest value this counter can hold.
+    const MAX: Self;
+
+    /// Returns `true` if the counter is zero.
+    fn is_zero(&self) -> bool;
+
+    /// Returns `true` if the counter has saturated at [`CounterStorage::MAX`].
+    fn is_saturated(&self) -> bool;
+
+    /// Increments the counter, clamping at [`CounterStorage::MAX`].
+    fn saturating_inc(&mut self);
+
+    /// Decrements the counter by one.
+    ///
+    /// Does nothing if the counter is already zero, or if it has saturated:
+    /// a saturated counter is "stuck" and decrementing it could wrongly zero
+    /// out a position that other elements still rely on.
+    fn saturating_dec(&mut self);
+}
+
+macro_rules! impl_counter_storage {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl CounterStorage for $ty {
+                const MAX: Self = <$ty>::MAX;
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+
+                #[inline]
+                fn is_saturated(&self) -> bool {
+                    *self == <$ty>::MAX
+                }
+
+                #[inline]
+                fn saturating_inc(&mut self) {
+                    *self = self.saturating_add(1);
+                }
+
+                #[inline]
+                fn saturating_dec(&mut self) {
+                    if *self != 0 && *self != <$ty>::MAX {
+                        *self -= 1;
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_counter_storage!(u8, u16);
+
+/// A counting Bloom filter supporting removal.
+///
+/// Where [`StandardBloomFilter`] stores a single bit per position, a counting
+/// Bloom filter stores a small counter. `insert` increments every addressed
+/// counter and `remove` decrements them, so an element can be deleted without
+/// introducing false negatives for other elements that share some of its
+/// positions.
+///
+/// # Type Parameters
+/// - `T`: The type of items to store. Must implement `Hash`.
+/// - `C`: The counter cell type (defaults to `u8`). Wider counters tolerate
+///   more reuse before saturating at the cost of memory.
+///
+/// # Example
+/// ```
+/// use tempora_bloom::CountingBloomFilter;
+///
+/// let mut filter: CountingBloomFilter<str> = CountingBloomFilter::new(1000, 0.01);
+/// filter.insert("hello");
+/// assert!(filter.contains("hello"));
+/// filter.remove("hello");
+/// assert!(!filter.contains("hello"));
+/// ```
+pub struct CountingBloomFilter<T: ?Sized + Hash, C: CounterStorage = u8> {
+    counters: Vec<C>,
+    optimal_k: u32,
+    hashers: [DefaultHasher; 2],
+    _marker: PhantomData<T>,
+}
+
+impl<T: ?Sized + Hash, C: CounterStorage> CountingBloomFilter<T, C> {
+    /// Creates a new counting Bloom filter optimized for the expected number of
+    /// items and desired false positive rate.
+    ///
+    /// # Arguments
+    /// * `items_count` - Expected number of items to be inserted
+    /// * `fp_rate` - Desired false positive rate (e.g., 0.01 for 1%)
+    ///
+    /// # Panics
+    /// Panics if `fp_rate` is not in the range (0, 1) or if `items_count` is 0.
+    pub fn new(items_count: usize, fp_rate: f64) -> Self {
+        assert!(items_count > 0, "items_count must be greater than 0");
+        assert!(
+            fp_rate > 0.0 && fp_rate < 1.0,
+            "fp_rate must be between 0 and 1 (exclusive)"
+        );
+
+        let optimal_m = bitmap_size(items_count, fp_rate);
+        let optimal_k = optimal_k(fp_rate);
+        let hashers = [
+            RandomState::new().build_hasher(),
+            RandomState::new().build_hasher(),
+        ];
+
+        Self {
+            counters: vec![C::default(); optimal_m],
+            optimal_k,
+            hashers,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Inserts an item, incrementing every addressed counter.
+    pub fn insert(&mut self, item: &T) {
+        let (h1, h2) = self.hash_kernel(item);
+        let len = self.counters.len();
+
+        for k_i in 0..self.optimal_k {
+            let index = get_index(h1, h2, k_i as u64, len);
+            self.counters[index].saturating_inc();
+        }
+    }
+
+    /// Removes an item, decrementing every addressed counter.
+    ///
+    /// Only remove items known to have been inserted. Because counters are
+    /// shared between elements whose hash positions collide, removing an item
+    /// that was never inserted — or removing an item more times than it was
+    /// inserted — can decrement a counter another element relies on and drive
+    /// it to zero, introducing a false negative for that element. Saturated
+    /// counters are left untouched, since a stuck counter can no longer be
+    /// decremented back to an accurate value.
+    pub fn remove(&mut self, item: &T) {
+        let (h1, h2) = self.hash_kernel(item);
+        let len = self.counters.len();
+
+        for k_i in 0..self.optimal_k {
+            let index = get_index(h1, h2, k_i as u64, len);
+            self.counters[index].saturating_dec();
+        }
+    }
+
+    /// Checks if an item might be in the filter.
+    ///
+    /// # Returns
+    /// * `true` if the item might be in the filter (can be a false positive)
+    /// * `false` if the item is definitely not in the filter
+    pub fn contains(&self, item: &T) -> bool {
+        let (h1, h2) = self.hash_kernel(item);
+        let len = self.counters.len();
+
+        for k_i in 0..self.optimal_k {
+            let index = get_index(h1, h2, k_i as u64, len);
+            if self.counters[index].is_zero() {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Clears all items from the filter.
+    pub fn clear(&mut self) {
+        for counter in &mut self.counters {
+            *counter = C::default();
+        }
+    }
+
+    /// Returns the number of counters in the underlying array.
+    pub fn len(&self) -> usize {
+        self.counters.len()
+    }
+
+    /// Returns `true` if every counter is zero.
+    pub fn is_empty(&self) -> bool {
+        self.counters.iter().all(CounterStorage::is_zero)
+    }
+
+    /// Returns the number of hash functions used.
+    pub fn hash_count(&self) -> u32 {
+        self.optimal_k
+    }
+
+    /// Computes two independent hash values for the given item.
+    fn hash_kernel(&self, item: &T) -> (u64, u64) {
+        let mut hasher1 = self.hashers[0].clone();
+        item.hash(&mut hasher1);
+        let h1 = hasher1.finish();
+
+        let mut hasher2 = self.hashers[1].clone();
+        item.hash(&mut hasher2);
+        let h2 = hasher2.finish();
+
+        (h1, h2)
     }
 }
 
@@ -211,4 +679,202 @@ mod tests {
     fn test_invalid_fp_rate_panics() {
         let _bloom: StandardBloomFilter<str> = StandardBloomFilter::new(100, 1.5);
     }
+
+    #[test]
+    fn test_with_custom_hashers() {
+        let mut bloom: StandardBloomFilter<str, RandomState> =
+            StandardBloomFilter::with_hashers(100, 0.01, RandomState::new(), RandomState::new());
+        bloom.insert("item");
+        assert!(bloom.contains("item"));
+        assert!(!bloom.contains("missing"));
+    }
+
+    #[test]
+    fn test_into_from_parts_round_trip() {
+        use std::hash::BuildHasherDefault;
+        type Det = BuildHasherDefault<DefaultHasher>;
+
+        let mut bloom: StandardBloomFilter<str, Det> =
+            StandardBloomFilter::with_hashers(100, 0.01, Det::default(), Det::default());
+        bloom.insert("apple");
+        bloom.insert("banana");
+
+        let (parts, k, seeds) = bloom.into_parts();
+        let restored: StandardBloomFilter<str, Det> =
+            StandardBloomFilter::from_parts(&parts, k, seeds);
+
+        assert!(restored.contains("apple"));
+        assert!(restored.contains("banana"));
+        assert_eq!(restored.len(), bloom.len());
+    }
+
+    #[test]
+    fn test_journal_tracks_touched_words() {
+        let mut bloom = StandardBloomFilter::new(100, 0.01);
+        bloom.enable_journal();
+        bloom.insert("x");
+
+        let journal = bloom.drain_journal();
+        assert!(!journal.is_empty());
+
+        // Draining a second time yields nothing new.
+        assert!(bloom.drain_journal().is_empty());
+    }
+
+    #[test]
+    fn test_union_preserves_membership() {
+        use std::hash::BuildHasherDefault;
+        type Det = BuildHasherDefault<DefaultHasher>;
+
+        let mut a: StandardBloomFilter<str, Det> =
+            StandardBloomFilter::with_hashers(1000, 0.01, Det::default(), Det::default());
+        a.insert("x");
+
+        let (parts, k, seeds) = a.into_parts();
+        let mut b: StandardBloomFilter<str, Det> =
+            StandardBloomFilter::from_parts(&vec![0u64; parts.len()], k, seeds);
+        b.insert("y");
+
+        a.union(&b);
+        assert!(a.contains("x"));
+        assert!(a.contains("y"));
+    }
+
+    #[test]
+    fn test_intersection_retains_common() {
+        use std::hash::BuildHasherDefault;
+        type Det = BuildHasherDefault<DefaultHasher>;
+
+        let mut a: StandardBloomFilter<str, Det> =
+            StandardBloomFilter::with_hashers(1000, 0.01, Det::default(), Det::default());
+        a.insert("x");
+        a.insert("y");
+
+        let (_parts, k, seeds) = a.into_parts();
+        let mut b: StandardBloomFilter<str, Det> =
+            StandardBloomFilter::from_parts(&vec![0u64; a.len() / 64], k, seeds);
+        b.insert("y");
+
+        a.intersection(&b);
+        assert!(a.contains("y"));
+    }
+
+    #[test]
+    #[should_panic(expected = "same bitmap length")]
+    fn test_union_incompatible_panics() {
+        let mut a: StandardBloomFilter<str> = StandardBloomFilter::new(100, 0.01);
+        let b: StandardBloomFilter<str> = StandardBloomFilter::new(1000, 0.01);
+        a.union(&b);
+    }
+
+    #[test]
+    fn test_count_ones_and_estimates() {
+        let mut bloom: StandardBloomFilter<i32> = StandardBloomFilter::new(1000, 0.01);
+        assert_eq!(bloom.count_ones(), 0);
+        assert_eq!(bloom.estimate_count(), 0.0);
+        assert_eq!(bloom.estimated_fp_rate(), 0.0);
+
+        for i in 0..100 {
+            bloom.insert(&i);
+        }
+
+        assert!(bloom.count_ones() > 0);
+        // The estimate should be in the right ballpark of the 100 inserts.
+        let estimate = bloom.estimate_count();
+        assert!(estimate > 50.0 && estimate < 200.0, "estimate was {estimate}");
+        assert!(bloom.estimated_fp_rate() > 0.0);
+    }
+
+    #[test]
+    fn test_estimate_saturated_is_infinite() {
+        let mut bloom = StandardBloomFilter::new(1, 0.5);
+        for i in 0..10_000 {
+            bloom.insert(&i);
+        }
+        if bloom.count_ones() == bloom.len() {
+            assert!(bloom.estimate_count().is_infinite());
+            assert_eq!(bloom.estimated_fp_rate(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_journal_disabled_by_default() {
+        let mut bloom = StandardBloomFilter::new(100, 0.01);
+        bloom.insert("x");
+        assert!(bloom.drain_journal().is_empty());
+    }
+
+    #[test]
+    fn test_counting_insert_and_contains() {
+        let mut bloom: CountingBloomFilter<str> = CountingBloomFilter::new(100, 0.01);
+        bloom.insert("item");
+        assert!(bloom.contains("item"));
+    }
+
+    #[test]
+    fn test_counting_remove() {
+        let mut bloom: CountingBloomFilter<str> = CountingBloomFilter::new(100, 0.01);
+        bloom.insert("item");
+        assert!(bloom.contains("item"));
+        bloom.remove("item");
+        assert!(!bloom.contains("item"));
+    }
+
+    #[test]
+    fn test_counting_remove_preserves_other_items() {
+        let mut bloom: CountingBloomFilter<str> = CountingBloomFilter::new(1000, 0.01);
+        bloom.insert("apple");
+        bloom.insert("banana");
+        bloom.remove("apple");
+        assert!(bloom.contains("banana"));
+    }
+
+    #[test]
+    fn test_counting_remove_absent_from_empty_filter_stays_empty() {
+        let mut bloom: CountingBloomFilter<str> = CountingBloomFilter::new(100, 0.01);
+        bloom.remove("never_inserted");
+        assert!(bloom.is_empty());
+    }
+
+    #[test]
+    fn test_counting_remove_never_inserted_can_cause_false_negative() {
+        // Removing an item that was never inserted is NOT generally safe: its
+        // hash positions may collide with a present element's, decrementing a
+        // shared counter to zero and dropping that element. We force a collision
+        // with a tiny filter so the contract is documented rather than assumed.
+        // Each attempt uses a freshly (randomly) seeded filter, so a single
+        // trial only collides ~half the time; repeat enough that never seeing
+        // the collision is astronomically unlikely.
+        let mut observed_false_negative = false;
+        for attempt in 0..64 {
+            let mut bloom: CountingBloomFilter<i32> = CountingBloomFilter::new(1, 0.5);
+            bloom.insert(&attempt);
+            bloom.remove(&-1);
+            if !bloom.contains(&attempt) {
+                observed_false_negative = true;
+                break;
+            }
+        }
+
+        assert!(
+            observed_false_negative,
+            "expected a collision that demonstrates the false-negative risk"
+        );
+    }
+
+    #[test]
+    fn test_counting_saturated_counter_is_stuck() {
+        let mut counter: u8 = u8::MAX;
+        counter.saturating_dec();
+        assert_eq!(counter, u8::MAX);
+    }
+
+    #[test]
+    fn test_counting_u16_storage() {
+        let mut bloom: CountingBloomFilter<str, u16> = CountingBloomFilter::new(100, 0.01);
+        bloom.insert("item");
+        assert!(bloom.contains("item"));
+        bloom.remove("item");
+        assert!(!bloom.contains("item"));
+    }
 }